@@ -0,0 +1,172 @@
+// A small tokenizer for the collision polygon line format: a sequence of "(x, y)" pairs separated
+// by commas, e.g. "(1, 2), (3.5, -4), (0, 0)". Unlike a plain `split`, this scans character by
+// character so stray whitespace or trailing punctuation doesn't break the parser, and it exposes
+// `get_pos`/`set_pos` so a caller can peek a token and "un-get" it by rewinding when it turns out
+// not to match what was expected.
+
+use std::fmt;
+
+// The kinds of token this lexer can produce out of a polygon line. `Word` covers the optional
+// trailing attribute token (a color name or a "#RRGGBB" hex code) that can follow a polygon's points.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    LParen,
+    RParen,
+    Comma,
+    Number(f32),
+    Word(String),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::LParen => write!(f, "`(`"),
+            Token::RParen => write!(f, "`)`"),
+            Token::Comma => write!(f, "`,`"),
+            Token::Number(n) => write!(f, "{}", n),
+            Token::Word(word) => write!(f, "\"{}\"", word),
+        }
+    }
+}
+
+// Scans a line one token at a time, tracking a byte offset into the input that the caller can
+// save and restore to implement arbitrary lookahead.
+pub struct Lexer<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Lexer { input, pos: 0 }
+    }
+
+    // The current byte offset into the line, for saving...
+    pub fn get_pos(&self) -> usize {
+        self.pos
+    }
+
+    // ... and restoring, which is how a caller "un-gets" a token it peeked but didn't want.
+    pub fn set_pos(&mut self, pos: usize) {
+        self.pos = pos;
+    }
+
+    // Skips leading whitespace, then reads the next token. Returns `None` at the end of the line,
+    // `Some(Err(text))` if the next characters don't form a recognized token (e.g. a malformed
+    // number or a stray symbol), and `Some(Ok(token))` otherwise.
+    pub fn next_token(&mut self) -> Option<Result<Token, String>> {
+        self.skip_whitespace();
+
+        let rest = &self.input[self.pos..];
+        let c = rest.chars().next()?;
+
+        match c {
+            '(' => {
+                self.pos += 1;
+                Some(Ok(Token::LParen))
+            }
+            ')' => {
+                self.pos += 1;
+                Some(Ok(Token::RParen))
+            }
+            ',' => {
+                self.pos += 1;
+                Some(Ok(Token::Comma))
+            }
+            '-' | '+' | '.' | '0'..='9' => {
+                // Greedily consume everything that could plausibly belong to a number, then let
+                // `f32::from_str` decide whether it actually is one.
+                let mut end = c.len_utf8();
+                for ch in rest[end..].chars() {
+                    if ch.is_ascii_digit() || ch == '.' {
+                        end += ch.len_utf8();
+                    } else {
+                        break;
+                    }
+                }
+
+                let text = &rest[..end];
+                self.pos += end;
+
+                match text.parse::<f32>() {
+                    Ok(n) => Some(Ok(Token::Number(n))),
+                    Err(_) => Some(Err(text.to_string())),
+                }
+            }
+            '#' | 'a'..='z' | 'A'..='Z' => {
+                // A color name or a "#RRGGBB" hex code: greedily consume letters/digits after the leading character.
+                let mut end = c.len_utf8();
+                for ch in rest[end..].chars() {
+                    if ch.is_ascii_alphanumeric() {
+                        end += ch.len_utf8();
+                    } else {
+                        break;
+                    }
+                }
+
+                let text = &rest[..end];
+                self.pos += end;
+                Some(Ok(Token::Word(text.to_string())))
+            }
+            other => {
+                self.pos += other.len_utf8();
+                Some(Err(other.to_string()))
+            }
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        let skip = rest.find(|c: char| !c.is_whitespace()).unwrap_or(rest.len());
+        self.pos += skip;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenizes_a_polygon_line() {
+        let mut lexer = Lexer::new("(1, 2), (3.5, -4), (0, 0) red");
+        let tokens: Vec<Token> = std::iter::from_fn(|| lexer.next_token())
+            .map(|result| result.unwrap())
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::LParen, Token::Number(1.0), Token::Comma, Token::Number(2.0), Token::RParen,
+                Token::Comma,
+                Token::LParen, Token::Number(3.5), Token::Comma, Token::Number(-4.0), Token::RParen,
+                Token::Comma,
+                Token::LParen, Token::Number(0.0), Token::Comma, Token::Number(0.0), Token::RParen,
+                Token::Word("red".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn set_pos_un_gets_a_peeked_token() {
+        let mut lexer = Lexer::new("(1, 2)");
+        lexer.next_token(); // LParen
+
+        let saved = lexer.get_pos();
+        assert_eq!(lexer.next_token(), Some(Ok(Token::Number(1.0))));
+
+        lexer.set_pos(saved);
+        assert_eq!(lexer.next_token(), Some(Ok(Token::Number(1.0))));
+    }
+
+    #[test]
+    fn reports_a_malformed_token() {
+        let mut lexer = Lexer::new("1.2.3");
+        assert_eq!(lexer.next_token(), Some(Err("1.2.3".to_string())));
+    }
+
+    #[test]
+    fn returns_none_at_end_of_input() {
+        let mut lexer = Lexer::new("   ");
+        assert_eq!(lexer.next_token(), None);
+    }
+}
@@ -1,114 +1,591 @@
+// Declare our lexer module, which tokenizes polygon lines for `get_polygon`.
+mod lexer;
+
 // Namespace imports to make the code less verbose.
-use std::{any, env, fs, path::Path, str::FromStr, time::Duration};
-use sdl2::{event::Event, EventPump, pixels::Color, rect::Point, render::Canvas, video::Window};
+use std::{any, env, fmt, fs, io, path::{Path, PathBuf}, process, str::FromStr, time::Duration};
+use sdl2::{event::Event, keyboard::Keycode, EventPump, pixels::Color, rect::{Point, Rect}, render::Canvas, video::Window};
+use lexer::{Lexer, Token};
+
+// Error type covering everything that can go wrong while locating and parsing a map directory.
+// Every variant carries the file it was found in (and a line number where that's meaningful) so
+// `main` can print a diagnostic that points straight at the offending line.
+#[derive(Debug)]
+enum MapError {
+    // A filesystem-level problem: the path doesn't exist, isn't a directory/file, or couldn't be read.
+    Io { file: PathBuf, source: io::Error },
+    // The metadata file doesn't have the shape it's supposed to (e.g. not two or three lines).
+    BadMetadata { file: PathBuf, line: usize, found: String },
+    // A single token inside an "x, y" pair didn't parse as the expected type.
+    BadPair { file: PathBuf, line: usize, token: String, expected_type: &'static str },
+    // The polygon tokenizer found something other than what the grammar expected at a given column.
+    BadToken { file: PathBuf, line: usize, column: usize, found: String, expected: &'static str },
+    // A polygon line produced zero points.
+    EmptyPolygon { file: PathBuf, line: usize },
+}
+
+impl fmt::Display for MapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapError::Io { file, source } => write!(f, "{}: {}", file.display(), source),
+            MapError::BadMetadata { file, line, found } => write!(f, "{}:{}: invalid metadata, found {}", file.display(), line, found),
+            MapError::BadPair { file, line, token, expected_type } => write!(f, "{}:{}: \"{}\" is not a valid {}", file.display(), line, token, expected_type),
+            MapError::BadToken { file, line, column, found, expected } => write!(f, "{}:{}:{}: expected {}, found {}", file.display(), line, column, expected, found),
+            MapError::EmptyPolygon { file, line } => write!(f, "{}:{}: polygon has no points", file.display(), line),
+        }
+    }
+}
+
+impl std::error::Error for MapError {}
 
-// Declare and define a function which panics (prints error and exits) if the given path does not resolve to anything.
-fn ensure_exists(path: &Path) {
+// A metadata file's parsed window size, pan offset, and whether polygons should be drawn filled.
+type MapMetadata = ((u32, u32), (i32, i32), bool);
+// A parsed polygon line: its raw world-space points, plus an optional trailing color token.
+type ParsedPolygon = (Vec<(f32, f32)>, Option<Color>);
+// Everything `read_files` loads for a map: its polygons, the window size, and the pan offset.
+type LoadedMap = (Vec<Polygon>, (u32, u32), (i32, i32));
+
+// Declare and define a function which returns an error if the given path does not resolve to anything.
+fn ensure_exists(path: &Path) -> Result<(), MapError> {
     if !path.exists() {
-        panic!("`{}` does not exist.", path.display());
+        return Err(MapError::Io { file: path.to_path_buf(), source: io::Error::new(io::ErrorKind::NotFound, "does not exist") });
     }
+    Ok(())
 }
 
-// Function which panics if the given path does not resolve or is not a directory.
-fn ensure_dir(path: &Path) {
-    ensure_exists(path);
+// Function which returns an error if the given path does not resolve or is not a directory.
+fn ensure_dir(path: &Path) -> Result<(), MapError> {
+    ensure_exists(path)?;
     if !path.is_dir() {
-        panic!("`{}` is not a directory.", path.display());
+        return Err(MapError::Io { file: path.to_path_buf(), source: io::Error::new(io::ErrorKind::InvalidInput, "is not a directory") });
     }
+    Ok(())
 }
 
-// Function which panics if the given path does not resolve or is not a file.
-fn ensure_file(path: &Path) {
-    ensure_exists(path);
+// Function which returns an error if the given path does not resolve or is not a file.
+fn ensure_file(path: &Path) -> Result<(), MapError> {
+    ensure_exists(path)?;
     if !path.is_file() {
-        panic!("`{}` is not a file.", path.display());
+        return Err(MapError::Io { file: path.to_path_buf(), source: io::Error::new(io::ErrorKind::InvalidInput, "is not a file") });
     }
+    Ok(())
 }
 
 // Function which takes a file path and reads and returns all of the text lines from it.
-fn read_lines(path: &Path) -> Vec<String> {
+fn read_lines(path: &Path) -> Result<Vec<String>, MapError> {
     // Read the file as a string, split it by line, construct a String from each fragment, tie it all into a vector, and return it.
-    fs::read_to_string(path).unwrap().lines().map(String::from).collect()
+    let contents = fs::read_to_string(path).map_err(|source| MapError::Io { file: path.to_path_buf(), source })?;
+    Ok(contents.lines().map(String::from).collect())
 }
 
 // Templated function which parses an "x, y" string into a tuple of x and y parsed into the requested type.
-fn get_pair<T: FromStr>(string: &str, list_error: &str, purpose_string: &str) -> (T, T) {
+fn get_pair<T: FromStr>(string: &str, file: &Path, line: usize) -> Result<(T, T), MapError> {
     // Split the "x, y" into "x", "y".
     let pair_strings: Vec<_> = string.split(", ").collect();
     if pair_strings.len() != 2 {
-        panic!("{}", list_error);
+        return Err(MapError::BadPair { file: file.to_path_buf(), line, token: string.to_string(), expected_type: "\"x, y\" pair" });
     }
-    // The return tuple.
-    (
-        // Parse "x" and "y" into the requested type, if failing giving a panic message which includes the name of the type.
-        pair_strings[0].parse::<T>().unwrap_or_else(|_| panic!("The x {} \"{}\" is not a valid {}.", purpose_string, pair_strings[0], any::type_name::<T>())),
-        pair_strings[1].parse::<T>().unwrap_or_else(|_| panic!("The y {} \"{}\" is not a valid {}.", purpose_string, pair_strings[1], any::type_name::<T>()))
-    )
+
+    // Parse "x" and "y" into the requested type, reporting the type name as a structured field rather than baking it into a message.
+    let x = pair_strings[0].parse::<T>().map_err(|_| MapError::BadPair {
+        file: file.to_path_buf(), line, token: pair_strings[0].to_string(), expected_type: any::type_name::<T>(),
+    })?;
+    let y = pair_strings[1].parse::<T>().map_err(|_| MapError::BadPair {
+        file: file.to_path_buf(), line, token: pair_strings[1].to_string(), expected_type: any::type_name::<T>(),
+    })?;
+
+    Ok((x, y))
 }
 
-// Function which parses a file with our metadata structure into the metadata pairs: size (unsigned) and offset (signed).
-fn get_metadata(path: &Path) -> ((u32, u32), (i32, i32)) {
-    let info = read_lines(path);
-    if info.len() != 2 {
-        panic!("`{}` does not have exactly two lines.", path.display());
+// Function which parses a file with our metadata structure into the metadata pairs (size and
+// offset) plus an optional third-line flag enabling filled rendering for every polygon parsed
+// from this directory; if that line is absent, polygons default to outline-only.
+fn get_metadata(path: &Path) -> Result<MapMetadata, MapError> {
+    let info = read_lines(path)?;
+    if info.len() != 2 && info.len() != 3 {
+        return Err(MapError::BadMetadata { file: path.to_path_buf(), line: info.len() + 1, found: format!("{} line(s) in the file", info.len()) });
     }
 
-    (
-        // Calls the templated function with the generic type set to u32, then i32, for each pair.
-        get_pair::<u32>(info[0].as_str(), format!("The size parameter (first line in `{}`) is not a pair \"x, y\".", path.display()).as_str(), "size"),
-        get_pair::<i32>(info[1].as_str(), format!("The offset parameter (second line in `{}`) is not a pair \"x, y\".", path.display()).as_str(), "offset")
-    )
+    // Calls the templated function with the generic type set to u32, then i32, for each pair.
+    let size_pair = get_pair::<u32>(info[0].as_str(), path, 1)?;
+    let offset_pair = get_pair::<i32>(info[1].as_str(), path, 2)?;
+
+    let filled = match info.get(2) {
+        Some(flag) => flag.trim().parse::<bool>().map_err(|_| MapError::BadMetadata {
+            file: path.to_path_buf(), line: 3, found: format!("\"{}\"", flag),
+        })?,
+        None => false,
+    };
+
+    Ok((size_pair, offset_pair, filled))
+}
+
+// Consumes the next token and checks it against `matches`, reporting a `BadToken` error (and
+// un-getting the offending token by rewinding the lexer) if it doesn't satisfy the predicate.
+fn expect_token(lexer: &mut Lexer, file: &Path, line: usize, expected: &'static str, matches: impl Fn(&Token) -> bool) -> Result<Token, MapError> {
+    let pos = lexer.get_pos();
+
+    match lexer.next_token() {
+        Some(Ok(token)) if matches(&token) => Ok(token),
+        Some(Ok(token)) => {
+            lexer.set_pos(pos);
+            Err(MapError::BadToken { file: file.to_path_buf(), line, column: pos + 1, found: token.to_string(), expected })
+        }
+        Some(Err(text)) => {
+            lexer.set_pos(pos);
+            Err(MapError::BadToken { file: file.to_path_buf(), line, column: pos + 1, found: text, expected })
+        }
+        None => Err(MapError::BadToken { file: file.to_path_buf(), line, column: pos + 1, found: "end of line".to_string(), expected }),
+    }
+}
+
+// Function which parses a color token — a named color like "red"/"blue", or a "#RRGGBB" hex code — into an `sdl2::pixels::Color`.
+fn parse_color(token: &str, file: &Path, line: usize, pos: usize) -> Result<Color, MapError> {
+    if let Some(hex) = token.strip_prefix('#') {
+        if let Some(color) = parse_hex_color(hex) {
+            return Ok(color);
+        }
+    } else if let Some(color) = named_color(token) {
+        return Ok(color);
+    }
+
+    Err(MapError::BadToken { file: file.to_path_buf(), line, column: pos + 1, found: token.to_string(), expected: "a color name or \"#RRGGBB\" hex code" })
+}
+
+// Parses the part of a "#RRGGBB" token after the `#`, returning `None` if it isn't exactly six hex digits.
+fn parse_hex_color(hex: &str) -> Option<Color> {
+    if hex.len() != 6 {
+        return None;
+    }
+
+    let channel = |range: std::ops::Range<usize>| hex.get(range).and_then(|s| u8::from_str_radix(s, 16).ok());
+    Some(Color::RGB(channel(0..2)?, channel(2..4)?, channel(4..6)?))
 }
 
-// Function which parses an "x, y" string of floats and an offset pair into an integer point with the offset applied, with the Y coordinate flipped prior for graphics reasons.
-fn get_polygon_point(string: &str, offset_pair: (i32, i32)) -> Point {
-    // Get the pair as a 32-bit floating-point number ("float" in Java, etc.).
-    let float_pair = get_pair::<f32>(string, format!("A polygon contains an invalid pair \"{}\"", string).as_str(), "coordinate");
-    // Construct the return point, flipping the Y coordinate and adding the offset coordinates.
-    Point::new(float_pair.0 as i32 + offset_pair.0, -float_pair.1 as i32 + offset_pair.1)
+// Function which maps a named color to its `sdl2::pixels::Color`, case-insensitively.
+fn named_color(name: &str) -> Option<Color> {
+    match name.to_lowercase().as_str() {
+        "red" => Some(Color::RED),
+        "green" => Some(Color::GREEN),
+        "blue" => Some(Color::BLUE),
+        "yellow" => Some(Color::YELLOW),
+        "cyan" => Some(Color::CYAN),
+        "magenta" => Some(Color::MAGENTA),
+        "white" => Some(Color::WHITE),
+        "black" => Some(Color::BLACK),
+        _ => None,
+    }
+}
+
+// Function which parses a string of "(x1, y1), (x2, y2), [...], (xf, yf)" into a list of raw world
+// coordinates plus an optional trailing color token, tolerating arbitrary spacing between tokens
+// since it scans via `Lexer` rather than splitting on a fixed separator string. The coordinates
+// are kept as-read (no offset or Y-flip applied) so the `Camera` can map them to screen space itself.
+fn get_polygon(string: &str, file: &Path, line: usize) -> Result<ParsedPolygon, MapError> {
+    let mut lexer = Lexer::new(string);
+    let mut points = Vec::new();
+    let mut first = true;
+
+    loop {
+        // Every point after the first is preceded by a comma; if one isn't there we un-get
+        // whatever we found and let the check below report it against the opening parenthesis.
+        if !first {
+            let pos = lexer.get_pos();
+            if !matches!(lexer.next_token(), Some(Ok(Token::Comma))) {
+                lexer.set_pos(pos);
+            }
+        }
+
+        // Peeking for the opening parenthesis doubles as our loop-exit check: running out of
+        // tokens here just means the line is done, not that it's malformed, and a word means the
+        // points are done and the optional trailing color token follows.
+        let pos = lexer.get_pos();
+        match lexer.next_token() {
+            None => break,
+            Some(Ok(Token::LParen)) => first = false,
+            Some(Ok(Token::Word(_))) => {
+                lexer.set_pos(pos);
+                break;
+            }
+            Some(Ok(token)) => {
+                lexer.set_pos(pos);
+                return Err(MapError::BadToken { file: file.to_path_buf(), line, column: pos + 1, found: token.to_string(), expected: "`(`" });
+            }
+            Some(Err(text)) => {
+                lexer.set_pos(pos);
+                return Err(MapError::BadToken { file: file.to_path_buf(), line, column: pos + 1, found: text, expected: "`(`" });
+            }
+        }
+
+        let x = match expect_token(&mut lexer, file, line, "a number", |t| matches!(t, Token::Number(_)))? {
+            Token::Number(n) => n,
+            _ => unreachable!(),
+        };
+        expect_token(&mut lexer, file, line, "`,`", |t| *t == Token::Comma)?;
+        let y = match expect_token(&mut lexer, file, line, "a number", |t| matches!(t, Token::Number(_)))? {
+            Token::Number(n) => n,
+            _ => unreachable!(),
+        };
+        expect_token(&mut lexer, file, line, "`)`", |t| *t == Token::RParen)?;
+
+        points.push((x, y));
+    }
+
+    // An optional trailing color token; if it isn't there, un-get whatever we found so a genuinely
+    // malformed line further down isn't silently swallowed here.
+    let pos = lexer.get_pos();
+    let color = match lexer.next_token() {
+        Some(Ok(Token::Word(word))) => Some(parse_color(&word, file, line, pos)?),
+        _ => {
+            lexer.set_pos(pos);
+            None
+        }
+    };
+
+    // Anything left after the points and the optional color token is malformed.
+    let pos = lexer.get_pos();
+    match lexer.next_token() {
+        None => {}
+        Some(Ok(token)) => return Err(MapError::BadToken { file: file.to_path_buf(), line, column: pos + 1, found: token.to_string(), expected: "end of line" }),
+        Some(Err(text)) => return Err(MapError::BadToken { file: file.to_path_buf(), line, column: pos + 1, found: text, expected: "end of line" }),
+    }
+
+    if points.is_empty() {
+        return Err(MapError::EmptyPolygon { file: file.to_path_buf(), line });
+    }
+
+    Ok((points, color))
+}
+
+// A parsed polygon: its raw world-space vertices, the color it's drawn with, and whether it
+// should be filled or just stroked. The `Camera` maps `points` to screen space every frame.
+struct Polygon {
+    points: Vec<(f32, f32)>,
+    color: Color,
+    filled: bool,
+}
+
+// A small fixed palette that polygons without an explicit color token cycle through by index, so
+// adjacent collision shapes in `polygons.txt` stay visually distinguishable.
+const DEFAULT_PALETTE: [Color; 6] = [Color::RED, Color::GREEN, Color::BLUE, Color::YELLOW, Color::CYAN, Color::MAGENTA];
+
+// Function which parses a file with a list of polygons' coordinates into a list of polygons, each
+// carrying its own color (explicit, or cycled from `DEFAULT_PALETTE` by index) and the directory's
+// filled-rendering flag.
+fn get_polygons(path: &Path, filled: bool) -> Result<Vec<Polygon>, MapError> {
+    read_lines(path)?.iter().enumerate().map(|(i, line_str)| {
+        let (points, color) = get_polygon(line_str, path, i + 1)?;
+        Ok(Polygon { points, color: color.unwrap_or(DEFAULT_PALETTE[i % DEFAULT_PALETTE.len()]), filled })
+    }).collect()
+}
+
+// A 2D camera mapping world-space polygon coordinates into screen space: `screen = (world + pan) *
+// zoom`, with the Y axis flipped so larger world Y values still draw higher up on screen.
+struct Camera {
+    pan: (f32, f32),
+    zoom: f32,
 }
 
-// Function which parses a string of "(x1, y1), (x2, y2), [...], (xf, yf)" into a list of points.
-fn get_polygon(string: &String, offset_pair: (i32, i32)) -> Vec<Point> {
-    // Split the "(x1, y1), (x2, y2), ..., (xf, yf)" into "(x1, y1", "x2, y2", ..., "xf, yf)".
-    let mut point_strings: Vec<_> = string.split("), (").collect();
+impl Camera {
+    fn to_screen(&self, world: (f32, f32)) -> Point {
+        Point::new(
+            ((world.0 + self.pan.0) * self.zoom).round() as i32,
+            (-(world.1 + self.pan.1) * self.zoom).round() as i32,
+        )
+    }
+}
+
+// Function which parses a Wavefront OBJ file into polygons: `v x y z` lines become vertices, and
+// `f i j k ...` lines become polygons built from those vertices (1-based indices; any trailing
+// "/texture/normal" part of a face index is ignored). Each vertex is projected to 2D by dropping Z.
+fn read_obj(path: &Path) -> Result<Vec<Polygon>, MapError> {
+    let mut vertices: Vec<(f32, f32)> = Vec::new();
+    let mut polygons = Vec::new();
+
+    for (i, line) in read_lines(path)?.iter().enumerate() {
+        let line_no = i + 1;
+        let mut tokens = line.split_whitespace();
 
-    // Fix the first and last strings by removing the parentheses.
-    let last_index = point_strings.len() - 1;
-    let fixed_first = point_strings[0].replace("(", "");
-    let fixed_last = point_strings[last_index].replace(")", "");
-    point_strings[0] = fixed_first.as_str();
-    point_strings[last_index] = fixed_last.as_str();
+        match tokens.next() {
+            Some("v") => {
+                let coords: Vec<f32> = tokens.filter_map(|t| t.parse::<f32>().ok()).collect();
+                if coords.len() < 2 {
+                    return Err(MapError::BadPair { file: path.to_path_buf(), line: line_no, token: line.clone(), expected_type: "a \"v x y z\" vertex line" });
+                }
+                vertices.push((coords[0], coords[1]));
+            }
+            Some("f") => {
+                let mut points = Vec::new();
+
+                for token in tokens {
+                    // A face index may carry "/texture/normal" suffixes; only the vertex index matters here.
+                    let index_str = token.split('/').next().unwrap_or(token);
+                    let index: usize = index_str.parse().map_err(|_| MapError::BadPair {
+                        file: path.to_path_buf(), line: line_no, token: token.to_string(), expected_type: "a 1-based vertex index",
+                    })?;
+                    let zero_based = index.checked_sub(1).ok_or_else(|| MapError::BadPair {
+                        file: path.to_path_buf(), line: line_no, token: token.to_string(), expected_type: "a 1-based vertex index",
+                    })?;
+                    let vertex = *vertices.get(zero_based).ok_or_else(|| MapError::BadPair {
+                        file: path.to_path_buf(), line: line_no, token: token.to_string(), expected_type: "a vertex index within range",
+                    })?;
+
+                    points.push(vertex);
+                }
+
+                if points.is_empty() {
+                    return Err(MapError::EmptyPolygon { file: path.to_path_buf(), line: line_no });
+                }
+
+                polygons.push(Polygon { points, color: DEFAULT_PALETTE[polygons.len() % DEFAULT_PALETTE.len()], filled: false });
+            }
+            // Every other OBJ record (texture coordinates, normals, comments, groups, ...) is irrelevant to a 2D collision preview.
+            _ => {}
+        }
+    }
 
-    // Take the list, process it with our polygon point function, tie it together into a vector, and return it.
-    // The very important thing to note here is that this and the previous iterator for point_strings do indeed process in order, otherwise our polygon would be jumbled.
-    point_strings.iter().map(|x| get_polygon_point(x, offset_pair)).collect()
+    Ok(polygons)
 }
 
-// Function which parses a file with a list of polygons' coordinates into a list of lists of points.
-fn get_polygons(path: &Path, offset_pair: (i32, i32)) -> Vec<Vec<Point>> {
-    // Take the list of point list strings, process it with our polygon function, tie it together into a vector, and return it.
-    read_lines(path).iter().map(|x| get_polygon(x, offset_pair)).collect()
+// Function which finds the smallest axis-aligned box containing every polygon's vertices, used to
+// size and offset the window when there's no `collision_info.txt` metadata to read it from.
+fn bounding_box(polygons: &[Polygon]) -> ((f32, f32), (f32, f32)) {
+    let mut min = (f32::INFINITY, f32::INFINITY);
+    let mut max = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+    for polygon in polygons {
+        for &(x, y) in &polygon.points {
+            min.0 = min.0.min(x);
+            min.1 = min.1.min(y);
+            max.0 = max.0.max(x);
+            max.1 = max.1.max(y);
+        }
+    }
+
+    (min, max)
 }
 
-// Function which parses a directory containing a metadata file and a list of polygons into the list of polygons and the dimensions.
-fn read_files(directory: String) -> (Vec<Vec<Point>>, (u32, u32)) {
+// Function which parses an input path into the list of polygons, the window dimensions, and the
+// offset used to seed the camera's pan. A `.obj` file is loaded as a mesh, with the window sized
+// and offset to fit its bounding box; anything else is treated as a directory containing
+// `collision_info.txt` and `polygons.txt` in our own text format.
+fn read_files(path_str: String) -> Result<LoadedMap, MapError> {
+    let path = Path::new(path_str.as_str());
+
+    if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("obj")) {
+        ensure_file(path)?;
+        let polygons = read_obj(path)?;
+
+        // Guard against an empty mesh before computing a bounding box (otherwise it'd be +/-infinity).
+        if polygons.is_empty() {
+            return Err(MapError::BadMetadata { file: path.to_path_buf(), line: 0, found: "no `f` faces in the OBJ file".to_string() });
+        }
+        let (min, max) = bounding_box(polygons.as_slice());
+
+        // Size the window to the mesh's bounding box, and offset so its top-left corner lands at the screen origin.
+        // `main` seeds the camera's pan as (offset.0, -offset.1), so the Y component here has to be
+        // `max.1` (not `-max.1`) to undo that negation and land back on the bbox's top edge.
+        let dimensions = ((max.0 - min.0).ceil() as u32, (max.1 - min.1).ceil() as u32);
+        let offset_pair = ((-min.0).round() as i32, max.1.round() as i32);
+
+        return Ok((polygons, dimensions, offset_pair));
+    }
+
     // Construct path object from input directory.
-    let dir_path = Path::new(directory.as_str());
+    let dir_path = path;
     // Derive path object from directory path object and filenames.
     let info_path = dir_path.join("collision_info.txt");
     let polygons_path = dir_path.join("polygons.txt");
 
     // Make sure our path actually exist and are what they are supposed to be in terms of paths vs. files.
-    ensure_dir(dir_path);
-    ensure_file(info_path.as_path());
-    ensure_file(polygons_path.as_path());
+    ensure_dir(dir_path)?;
+    ensure_file(info_path.as_path())?;
+    ensure_file(polygons_path.as_path())?;
 
     // Initialize multiple variables at once to the members of the tuple returned by the function.
-    let (size_pair, offset_pair) = get_metadata(info_path.as_path());
+    let (size_pair, offset_pair, filled) = get_metadata(info_path.as_path())?;
+
+    // Return tuple from our list of polygons, the parsed dimensions, and the offset.
+    Ok((get_polygons(polygons_path.as_path(), filled)?, size_pair, offset_pair))
+}
+
+// One of the four edges of a window that together form a Sutherland–Hodgman clip boundary.
+enum ClipEdge {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl ClipEdge {
+    // True if `point` is on the inside of this edge, i.e. within the window along that axis.
+    fn is_inside(&self, point: Point, bounds: Rect) -> bool {
+        match self {
+            ClipEdge::Top => point.y >= bounds.top(),
+            ClipEdge::Right => point.x <= bounds.right(),
+            ClipEdge::Bottom => point.y <= bounds.bottom(),
+            ClipEdge::Left => point.x >= bounds.left(),
+        }
+    }
+
+    // Where segment `prev -> cur` crosses this edge's boundary line, via linear interpolation
+    // `p = prev + t*(cur - prev)` solved for whichever coordinate this edge fixes.
+    fn intersect(&self, prev: Point, cur: Point, bounds: Rect) -> Point {
+        match self {
+            ClipEdge::Top | ClipEdge::Bottom => {
+                let boundary_y = if matches!(self, ClipEdge::Top) { bounds.top() } else { bounds.bottom() };
+                let t = (boundary_y - prev.y) as f32 / (cur.y - prev.y) as f32;
+                Point::new((prev.x as f32 + t * (cur.x - prev.x) as f32).round() as i32, boundary_y)
+            }
+            ClipEdge::Left | ClipEdge::Right => {
+                let boundary_x = if matches!(self, ClipEdge::Left) { bounds.left() } else { bounds.right() };
+                let t = (boundary_x - prev.x) as f32 / (cur.x - prev.x) as f32;
+                Point::new(boundary_x, (prev.y as f32 + t * (cur.y - prev.y) as f32).round() as i32)
+            }
+        }
+    }
+}
+
+// Function which clips a polygon to a window using the Sutherland–Hodgman algorithm: the vertex
+// list is walked once per clip edge in turn, each pass consuming the previous pass's output, so a
+// vertex outside multiple edges gets whittled down to the boundary across several small steps
+// rather than all at once. An empty result means the polygon was entirely outside the window.
+fn clip_polygon(poly: &[Point], bounds: Rect) -> Vec<Point> {
+    let mut output = poly.to_vec();
+
+    for edge in [ClipEdge::Top, ClipEdge::Right, ClipEdge::Bottom, ClipEdge::Left] {
+        if output.is_empty() {
+            break;
+        }
+
+        let input = output;
+        output = Vec::new();
+
+        // Treat the vertex list as closed: the edge "prev -> cur" wraps from the last point back to the first.
+        for i in 0..input.len() {
+            let cur = input[i];
+            let prev = input[(i + input.len() - 1) % input.len()];
+
+            let cur_inside = edge.is_inside(cur, bounds);
+            let prev_inside = edge.is_inside(prev, bounds);
+
+            // Whenever the edge crosses the boundary, emit the intersection point; always emit `cur` if it's inside.
+            if cur_inside != prev_inside {
+                output.push(edge.intersect(prev, cur, bounds));
+            }
+            if cur_inside {
+                output.push(cur);
+            }
+        }
+    }
+
+    output
+}
+
+// Function which writes the parsed polygons out as a standalone SVG document, one `<path>` per polygon.
+// `camera` projects the raw world coordinates to screen space exactly like the SDL window would at
+// that pan/zoom, so a `viewBox` of "0 0 width height" lines the SVG up pixel-for-pixel with it.
+fn dump_svg(polygons: &[Polygon], dimensions: (u32, u32), path: &Path, camera: &Camera) {
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {} {}\" width=\"{}\" height=\"{}\">\n",
+        dimensions.0, dimensions.1, dimensions.0, dimensions.1
+    );
+
+    // One `<path>` element per polygon: move to the first point, line to the rest, then close it.
+    for polygon in polygons {
+        if polygon.points.is_empty() {
+            continue;
+        }
+
+        let screen_points: Vec<Point> = polygon.points.iter().map(|&world| camera.to_screen(world)).collect();
+
+        let mut path_data = format!("M {} {}", screen_points[0].x, screen_points[0].y);
+        for point in &screen_points[1..] {
+            path_data.push_str(format!(" L {} {}", point.x, point.y).as_str());
+        }
+        path_data.push_str(" Z");
+
+        let hex = format!("#{:02x}{:02x}{:02x}", polygon.color.r, polygon.color.g, polygon.color.b);
+        let fill = if polygon.filled { hex.as_str() } else { "none" };
+        svg.push_str(format!("  <path d=\"{}\" stroke=\"{}\" fill=\"{}\"/>\n", path_data, hex, fill).as_str());
+    }
+
+    svg.push_str("</svg>\n");
+    fs::write(path, svg).unwrap();
+}
+
+// Function which renders one frame: every polygon is projected from world space to screen space
+// through `camera`, clipped to the window, and stroked (and optionally filled).
+fn render_frame(canvas: &mut Canvas<Window>, polygons: &[Polygon], dimensions: (u32, u32), camera: &Camera) {
+    canvas.set_draw_color(Color::BLACK);
+    canvas.clear();
+
+    let window_bounds = Rect::new(0, 0, dimensions.0, dimensions.1);
+
+    for polygon in polygons {
+        let screen_points: Vec<Point> = polygon.points.iter().map(|&world| camera.to_screen(world)).collect();
+        let clipped = clip_polygon(screen_points.as_slice(), window_bounds);
+        // A polygon fully outside the window clips down to nothing, and one reduced to a single point can't be stroked.
+        if clipped.len() < 2 {
+            continue;
+        }
+
+        canvas.set_draw_color(polygon.color);
+
+        if polygon.filled {
+            fill_polygon(canvas, clipped.as_slice());
+        }
+
+        canvas.draw_lines(clipped.as_slice()).unwrap();
+        canvas.draw_line(clipped[0], clipped[clipped.len() - 1]).unwrap();
+    }
+
+    canvas.present();
+}
+
+// Function which finds where a scanline at height `y` crosses the polygon's edges, sorted left to
+// right. Pulled out of `fill_polygon` so the scanline math can be exercised without an SDL canvas.
+fn scanline_crossings(points: &[Point], y: i32) -> Vec<i32> {
+    let mut crossings = Vec::new();
+
+    for i in 0..points.len() {
+        let a = points[i];
+        let b = points[(i + 1) % points.len()];
+
+        // Horizontal edges don't contribute a crossing at any scanline.
+        if a.y == b.y {
+            continue;
+        }
+
+        // Half-open test on the edge's Y span so a vertex shared by two edges isn't counted twice.
+        let (lo, hi) = if a.y < b.y { (a, b) } else { (b, a) };
+        if y >= lo.y && y < hi.y {
+            let t = (y - lo.y) as f32 / (hi.y - lo.y) as f32;
+            crossings.push((lo.x as f32 + t * (hi.x - lo.x) as f32).round() as i32);
+        }
+    }
+
+    crossings.sort_unstable();
+    crossings
+}
+
+// Function which fills a simple polygon via horizontal scanlines: for each row within the
+// polygon's vertical extent, finds the x-coordinates where the polygon's edges cross that row,
+// sorts them, and draws a horizontal line between each successive pair.
+fn fill_polygon(canvas: &mut Canvas<Window>, points: &[Point]) {
+    if points.len() < 3 {
+        return;
+    }
 
-    // Return tuple from our list of lists of points and the parsed dimensions.
-    (get_polygons(polygons_path.as_path(), offset_pair), size_pair)
+    let min_y = points.iter().map(|p| p.y).min().unwrap();
+    let max_y = points.iter().map(|p| p.y).max().unwrap();
+
+    for y in min_y..=max_y {
+        for pair in scanline_crossings(points, y).chunks(2) {
+            if let [x1, x2] = pair {
+                canvas.draw_line(Point::new(*x1, y), Point::new(*x2, y)).unwrap();
+            }
+        }
+    }
 }
 
 // Function which initializes the SDL2 library and returns a tuple with handles to a canvas and to an event queue.
@@ -125,58 +602,362 @@ fn init_sdl2(dimensions: (u32, u32)) -> (Canvas<Window>, EventPump) {
     )
 }
 
+// Function which walks the trailing CLI arguments looking for `--svg <path>` and `--headless`, returning
+// the requested SVG output path (if any) and whether the SDL window should be skipped entirely.
+fn parse_output_flags(args: &[String]) -> (Option<PathBuf>, bool) {
+    let mut svg_path = None;
+    let mut headless = false;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--svg" => {
+                let path = args.get(i + 1).unwrap_or_else(|| panic!("`--svg` requires an output path."));
+                svg_path = Some(PathBuf::from(path));
+                i += 2;
+            }
+            "--headless" => {
+                headless = true;
+                i += 1;
+            }
+            other => panic!("Unrecognized argument `{}`.", other),
+        }
+    }
+
+    (svg_path, headless)
+}
+
 // Defines the entrypoint function.
 fn main() {
     // Collects the equivalent of C's argc and argv into a list of arguments.
     let args: Vec<_> = env::args().collect();
-    // If the user did not provide the right number of arguments...
-    if args.len() != 2 {
+    // If the user did not provide at least a directory...
+    if args.len() < 2 {
         // ... tell the user how to use the program and terminate.
-        println!("Usage: {} <path>", args[0]);
-    }
-    // Otherwise, proceed with the program.
-    else {
-        // Feed the directory supplied by the user into our parse functions.
-        let (polygon_list, dimensions) = read_files(args[1].clone());
-
-        // Get our canvas and event queue handles.
-        let (mut canvas, mut event_pump) = init_sdl2(dimensions);
-
-        // Set what will be used as a frame duration to 1 billion microseconds integer divided by 60, which means targeting 60 FPS.
-        let frame_duration = Duration::new(0, 1_000_000_000 / 60);
-
-        // Tell the canvas draw code that the following draw or fill commands should be done with the built-in color red.
-        canvas.set_draw_color(Color::RED);
-
-        // For every list of points in our polygon list...
-        for polygon in polygon_list {
-            // ... draw a path through each point...
-            canvas.draw_lines(polygon.as_slice()).unwrap();
-            // ... and draw a line from the end to the beginning.
-            canvas.draw_line(polygon[0], polygon[polygon.len() - 1]).unwrap();
-        }
-
-        // SDL2 does multi-buffering, and this is how we instruct the library to show the framebuffer we've been drawing on.
-        canvas.present();
-
-        // This loop is needed only because the program terminates when main returns, and the window closes when the program terminates.
-        // The loop is labelled so it can be broken from an inner loop.
-        'outer: loop {
-            // Loop over every new event since the last check.
-            for event in event_pump.poll_iter() {
-                // Switch over every type of event.
-                match event {
-                    // Quit event is when the user clicks the window's close button or uses any similar polite OS close feature.
-                    // Jumps out of the outer loop, which in this case is at the end of the program.
-                    // If any cleanup code was needed, it could come after the outer loop.
-                    Event::Quit { .. } => break 'outer,
-                    // We do not care about any other events.
+        println!("Usage: {} <path> [--svg <output.svg>] [--headless]", args[0]);
+        return;
+    }
+
+    // Feed the directory supplied by the user into our parse functions, printing a diagnostic and
+    // exiting nonzero instead of panicking if anything about the map directory is malformed.
+    let (polygon_list, dimensions, offset_pair) = match read_files(args[1].clone()) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    };
+
+    // Any arguments past the directory configure SVG export and whether to skip the SDL window.
+    let (svg_path, headless) = parse_output_flags(&args[2..]);
+
+    // The camera starts panned to the metadata offset at 1:1 zoom, matching what a non-interactive
+    // render would have looked like before panning/zooming were possible. `to_screen` flips Y after
+    // adding the pan, so the Y offset has to be negated here to undo that flip and land back on the
+    // original "flip first, then add the offset" convention the offset field was defined against.
+    let mut camera = Camera { pan: (offset_pair.0 as f32, -offset_pair.1 as f32), zoom: 1.0 };
+
+    // If an SVG output path was requested, write the polygons out to it before doing anything else.
+    if let Some(svg_path) = svg_path.as_ref() {
+        dump_svg(polygon_list.as_slice(), dimensions, svg_path.as_path(), &camera);
+    }
+
+    // `--headless` lets the SVG export run without a display, e.g. in CI.
+    if headless {
+        return;
+    }
+
+    // Get our canvas and event queue handles.
+    let (mut canvas, mut event_pump) = init_sdl2(dimensions);
+
+    // Set what will be used as a frame duration to 1 billion nanoseconds integer divided by 60, which means targeting 60 FPS.
+    let frame_duration = Duration::new(0, 1_000_000_000 / 60);
+
+    render_frame(&mut canvas, polygon_list.as_slice(), dimensions, &camera);
+
+    // This loop is needed only because the program terminates when main returns, and the window closes when the program terminates.
+    // The loop is labelled so it can be broken from an inner loop.
+    'outer: loop {
+        // Panning speed is scaled by zoom so a key-press moves the view by the same apparent
+        // screen distance regardless of how far in we've zoomed.
+        let pan_step = 20.0 / camera.zoom;
+        // Captured before the event loop, since `poll_iter` holds `event_pump` mutably for its duration.
+        let mouse = event_pump.mouse_state();
+        let cursor = (mouse.x() as f32, mouse.y() as f32);
+
+        // Loop over every new event since the last check.
+        for event in event_pump.poll_iter() {
+            // Switch over every type of event.
+            match event {
+                // Quit event is when the user clicks the window's close button or uses any similar polite OS close feature.
+                // Jumps out of the outer loop, which in this case is at the end of the program.
+                // If any cleanup code was needed, it could come after the outer loop.
+                Event::Quit { .. } => break 'outer,
+                // Arrow keys and WASD pan the camera.
+                Event::KeyDown { keycode: Some(keycode), .. } => match keycode {
+                    Keycode::Left | Keycode::A => camera.pan.0 += pan_step,
+                    Keycode::Right | Keycode::D => camera.pan.0 -= pan_step,
+                    Keycode::Up | Keycode::W => camera.pan.1 += pan_step,
+                    Keycode::Down | Keycode::S => camera.pan.1 -= pan_step,
                     _ => {}
+                },
+                // The mouse wheel zooms, keeping the world point under the cursor fixed on-screen.
+                Event::MouseWheel { y, .. } => {
+                    let world_under_cursor = (cursor.0 / camera.zoom - camera.pan.0, -cursor.1 / camera.zoom - camera.pan.1);
+
+                    camera.zoom = (camera.zoom * 1.1_f32.powi(y)).clamp(0.05, 20.0);
+
+                    camera.pan.0 = cursor.0 / camera.zoom - world_under_cursor.0;
+                    camera.pan.1 = -cursor.1 / camera.zoom - world_under_cursor.1;
                 }
+                // We do not care about any other events.
+                _ => {}
             }
+        }
+
+        // Re-stroke every frame so panning/zooming updates are visible immediately.
+        render_frame(&mut canvas, polygon_list.as_slice(), dimensions, &camera);
+
+        // Block this thread for our specified duration (which we set such that the program runs at about 60 FPS).
+        std::thread::sleep(frame_duration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_polygon_parses_points_and_trailing_color() {
+        let path = Path::new("polygons.txt");
+        let (points, color) = get_polygon("(1, 2), (3.5, -4), (0, 0) red", path, 1).unwrap();
+
+        assert_eq!(points, vec![(1.0, 2.0), (3.5, -4.0), (0.0, 0.0)]);
+        assert_eq!(color, Some(Color::RED));
+    }
+
+    #[test]
+    fn get_polygon_without_a_color_returns_none() {
+        let path = Path::new("polygons.txt");
+        let (points, color) = get_polygon("(1, 2), (3, 4)", path, 1).unwrap();
+
+        assert_eq!(points, vec![(1.0, 2.0), (3.0, 4.0)]);
+        assert_eq!(color, None);
+    }
+
+    #[test]
+    fn get_polygon_rejects_an_empty_line() {
+        let path = Path::new("polygons.txt");
+        assert!(matches!(get_polygon("", path, 1), Err(MapError::EmptyPolygon { .. })));
+    }
+
+    #[test]
+    fn get_polygon_rejects_a_malformed_pair() {
+        let path = Path::new("polygons.txt");
+        assert!(matches!(get_polygon("(1, )", path, 1), Err(MapError::BadToken { .. })));
+    }
+
+    #[test]
+    fn clip_polygon_leaves_a_fully_inside_polygon_untouched() {
+        let bounds = Rect::new(0, 0, 100, 100);
+        let square = vec![Point::new(10, 10), Point::new(20, 10), Point::new(20, 20), Point::new(10, 20)];
+
+        assert_eq!(clip_polygon(&square, bounds), square);
+    }
+
+    #[test]
+    fn clip_polygon_drops_a_fully_outside_polygon() {
+        let bounds = Rect::new(0, 0, 100, 100);
+        let square = vec![Point::new(200, 200), Point::new(210, 200), Point::new(210, 210), Point::new(200, 210)];
+
+        assert!(clip_polygon(&square, bounds).is_empty());
+    }
+
+    #[test]
+    fn clip_polygon_cuts_a_polygon_straddling_an_edge() {
+        let bounds = Rect::new(0, 0, 100, 100);
+        // A square centered on the right edge of the window: half inside, half outside.
+        let square = vec![Point::new(80, 40), Point::new(120, 40), Point::new(120, 60), Point::new(80, 60)];
+
+        let clipped = clip_polygon(&square, bounds);
+
+        assert!(clipped.iter().all(|p| p.x <= bounds.right()));
+        assert!(clipped.iter().any(|p| p.x == bounds.right()));
+    }
+
+    #[test]
+    fn to_screen_applies_pan_then_flips_y() {
+        let camera = Camera { pan: (5.0, 5.0), zoom: 1.0 };
+        assert_eq!(camera.to_screen((0.0, 0.0)), Point::new(5, -5));
+    }
+
+    #[test]
+    fn to_screen_applies_zoom_after_panning() {
+        let camera = Camera { pan: (0.0, 0.0), zoom: 2.0 };
+        assert_eq!(camera.to_screen((10.0, 10.0)), Point::new(20, -20));
+    }
+
+    // `main` seeds the camera's pan from the metadata offset so the initial render matches the old,
+    // non-interactive "flip first, then add the offset" convention: screen.y = -world.y + offset.y.
+    // Since `to_screen` adds the pan before flipping, that means `pan.y` has to be `-offset.y`.
+    #[test]
+    fn pan_seeded_from_offset_matches_flip_then_add_convention() {
+        let offset = (0, 50);
+        let camera = Camera { pan: (offset.0 as f32, -offset.1 as f32), zoom: 1.0 };
+
+        let world_y = 20.0;
+        let expected_screen_y = -world_y + offset.1 as f32;
+
+        assert_eq!(camera.to_screen((0.0, world_y)).y, expected_screen_y.round() as i32);
+    }
+
+    // `read_obj` only takes a path, so these tests round-trip through a real temp file rather than
+    // a string, matching how the other file-reading code (`get_metadata`) is tested.
+    fn write_temp_file(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn write_temp_obj(name: &str, contents: &str) -> PathBuf {
+        write_temp_file(name, contents)
+    }
+
+    #[test]
+    fn get_metadata_parses_size_and_offset_without_a_filled_flag() {
+        let path = write_temp_file("exomap_test_metadata_2line.txt", "800, 600\n10, -20\n");
+
+        let (size_pair, offset_pair, filled) = get_metadata(path.as_path()).unwrap();
+
+        assert_eq!(size_pair, (800, 600));
+        assert_eq!(offset_pair, (10, -20));
+        assert!(!filled);
+    }
+
+    #[test]
+    fn get_metadata_parses_the_optional_filled_flag() {
+        let path = write_temp_file("exomap_test_metadata_3line.txt", "800, 600\n10, -20\ntrue\n");
+
+        let (_, _, filled) = get_metadata(path.as_path()).unwrap();
+
+        assert!(filled);
+    }
+
+    #[test]
+    fn get_metadata_rejects_a_file_with_the_wrong_line_count() {
+        let path = write_temp_file("exomap_test_metadata_badcount.txt", "800, 600\n");
+
+        assert!(matches!(get_metadata(path.as_path()), Err(MapError::BadMetadata { .. })));
+    }
+
+    #[test]
+    fn get_metadata_rejects_a_malformed_filled_flag() {
+        let path = write_temp_file("exomap_test_metadata_badflag.txt", "800, 600\n10, -20\nnot_a_bool\n");
+
+        assert!(matches!(get_metadata(path.as_path()), Err(MapError::BadMetadata { .. })));
+    }
+
+    #[test]
+    fn parse_color_reads_hex() {
+        let path = Path::new("polygons.txt");
+        assert_eq!(parse_color("#ff8000", path, 1, 0).unwrap(), Color::RGB(0xff, 0x80, 0x00));
+    }
+
+    #[test]
+    fn parse_color_rejects_a_hex_code_of_the_wrong_length() {
+        let path = Path::new("polygons.txt");
+        assert!(matches!(parse_color("#fff", path, 1, 0), Err(MapError::BadToken { .. })));
+    }
+
+    #[test]
+    fn parse_color_reads_named_colors_case_insensitively() {
+        let path = Path::new("polygons.txt");
+        assert_eq!(parse_color("Red", path, 1, 0).unwrap(), Color::RED);
+        assert_eq!(parse_color("BLUE", path, 1, 0).unwrap(), Color::BLUE);
+    }
+
+    #[test]
+    fn parse_color_rejects_an_unknown_word() {
+        let path = Path::new("polygons.txt");
+        assert!(matches!(parse_color("chartreuse", path, 1, 0), Err(MapError::BadToken { .. })));
+    }
+
+    #[test]
+    fn named_color_is_case_insensitive_and_falls_back_to_none() {
+        assert_eq!(named_color("Green"), Some(Color::GREEN));
+        assert_eq!(named_color("not_a_color"), None);
+    }
+
+    #[test]
+    fn scanline_crossings_finds_both_sides_of_a_square() {
+        let square = vec![Point::new(0, 0), Point::new(10, 0), Point::new(10, 10), Point::new(0, 10)];
+        assert_eq!(scanline_crossings(&square, 5), vec![0, 10]);
+    }
+
+    #[test]
+    fn scanline_crossings_ignores_horizontal_edges() {
+        // A flat-bottomed triangle: the bottom edge is horizontal and must not contribute a crossing.
+        let triangle = vec![Point::new(0, 10), Point::new(10, 10), Point::new(5, 0)];
+        assert_eq!(scanline_crossings(&triangle, 10), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn scanline_crossings_counts_a_shared_vertex_once() {
+        // The scanline through y=5 passes through the middle vertex at x=5, which two edges share;
+        // the half-open edge test should report a single crossing there, not two, alongside the
+        // crossing of the vertical left edge at x=0.
+        let triangle = vec![Point::new(0, 0), Point::new(5, 5), Point::new(0, 10)];
+        assert_eq!(scanline_crossings(&triangle, 5), vec![0, 5]);
+    }
+
+    #[test]
+    fn read_obj_builds_a_polygon_from_a_face() {
+        let path = write_temp_obj("exomap_test_triangle.obj", "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n");
+
+        let polygons = read_obj(path.as_path()).unwrap();
+
+        assert_eq!(polygons.len(), 1);
+        assert_eq!(polygons[0].points, vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]);
+    }
+
+    #[test]
+    fn read_obj_ignores_texture_and_normal_suffixes_on_face_indices() {
+        let path = write_temp_obj("exomap_test_suffixed.obj", "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1/4/7 2/5/8 3/6/9\n");
+
+        let polygons = read_obj(path.as_path()).unwrap();
+
+        assert_eq!(polygons[0].points, vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]);
+    }
+
+    #[test]
+    fn read_obj_rejects_a_face_index_out_of_range() {
+        let path = write_temp_obj("exomap_test_oob.obj", "v 0 0 0\nf 1 2\n");
+
+        assert!(matches!(read_obj(path.as_path()), Err(MapError::BadPair { .. })));
+    }
+
+    #[test]
+    fn bounding_box_covers_every_vertex() {
+        let polygons = vec![
+            Polygon { points: vec![(-1.0, 2.0), (3.0, -4.0)], color: Color::RED, filled: false },
+            Polygon { points: vec![(5.0, 0.0)], color: Color::RED, filled: false },
+        ];
+
+        assert_eq!(bounding_box(&polygons), ((-1.0, -4.0), (5.0, 2.0)));
+    }
+
+    // End-to-end: the offset `read_files` derives for an OBJ mesh, fed through the same pan-seeding
+    // and `Camera::to_screen` formulas `main` uses, must land the mesh's bounding box inside the window.
+    #[test]
+    fn read_files_offsets_an_obj_mesh_onto_screen() {
+        let path = write_temp_obj("exomap_test_onscreen.obj", "v 0 0 0\nv 10 0 0\nv 0 10 0\nf 1 2 3\n");
+
+        let (polygons, dimensions, offset_pair) = read_files(path.to_str().unwrap().to_string()).unwrap();
+        let camera = Camera { pan: (offset_pair.0 as f32, -offset_pair.1 as f32), zoom: 1.0 };
 
-            // Block this thread for our specified duration (which we set such that the program runs at about 60 FPS).
-            std::thread::sleep(frame_duration);
+        for point in polygons[0].points.iter().map(|&world| camera.to_screen(world)) {
+            assert!((0..=dimensions.0 as i32).contains(&point.x));
+            assert!((0..=dimensions.1 as i32).contains(&point.y));
         }
     }
 }